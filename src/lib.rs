@@ -59,29 +59,167 @@
 //! ```ignore
 //! #ignore
 //! ```
+//!
+//! # Case-Conversion Modifiers
+//!
+//! A use-site `#name:@modifier` re-cases the declared value before substituting it, rather than
+//! requiring a separate declaration for every casing you need. Supported modifiers are `upper`,
+//! `lower`, `snake`, `camel`, `pascal` and `screaming`. The marker is `:@modifier`, not bare
+//! `:modifier` — a bare colon would be indistinguishable from a real Rust `: Trait` bound or
+//! `: Type` annotation that happens to follow a substituted identifier (e.g. a generic bound
+//! `<#name: Clone>`), and could silently swallow it:
+//!
+//! ```
+//! ident_str::ident_str! {
+//!     #name = "hello_world" =>
+//!     fn #name:@camel() -> &'static str {
+//!         stringify!(#name:@screaming)
+//!     }
+//! }
+//!
+//! # fn main() {
+//! #     assert_eq!(helloWorld(), "HELLO_WORLD");
+//! # }
+//! ```
+//!
+//! # Inline Concatenation
+//!
+//! `#[< ... >]` glues several pieces into a single identifier, so you don't have to declare every
+//! combination up front (a plain `#[ ... ]`, like an attribute's `#[derive(...)]`, is left alone).
+//! Each piece may be a declared `#ident`, a string literal or a bare ident:
+//!
+//! ```
+//! ident_str::ident_str! {
+//!     #prefix = "foo",
+//!     #name = "bar"
+//!     =>
+//!     fn #[< #prefix "_" #name "_handler" >]() -> &'static str {
+//!         stringify!(#[< #prefix "_" #name "_handler" >])
+//!     }
+//! }
+//!
+//! # fn main() {
+//! #     assert_eq!(foo_bar_handler(), "foo_bar_handler");
+//! # }
+//! ```
+//!
+//! # Fallback Values
+//!
+//! `#name = <primary> | <fallback>` tries `<primary>` first and only falls back to `<fallback>`
+//! if `<primary>` doesn't evaluate to a legal identifier. This lets declarative macros provide a
+//! stable default for a fragment that may be empty or otherwise invalid:
+//!
+//! ```
+//! macro_rules! my_macro {
+//!     ($($opt: ident)?) => {
+//!         ident_str::ident_str! {
+//!             #name = concat!(stringify!($($opt)?)) | "fallback"
+//!             =>
+//!             fn #name() -> &'static str {
+//!                 stringify!(#name)
+//!             }
+//!         }
+//!     };
+//! }
+//!
+//! my_macro!();
+//!
+//! # fn main() {
+//! #     assert_eq!(fallback(), "fallback");
+//! # }
+//! ```
+//!
+//! # Repetition
+//!
+//! A declaration can bind a list, e.g. `#variant = ["Red", "Green", "Blue"]`, and a `#*{ ... }`
+//! group in the body re-emits its contents once per element, substituting the element on each
+//! pass. A list-bound variable may only be used inside a repetition group that references it; all
+//! list-bound variables referenced in the same group must have equal length.
+//!
+//! ```
+//! ident_str::ident_str! {
+//!     #variant = ["Red", "Green", "Blue"]
+//!     =>
+//!     enum Color {
+//!         #*{ #variant, }
+//!     }
+//! }
+//!
+//! # fn main() {
+//! #     let _ = Color::Red;
+//! #     let _ = Color::Green;
+//! #     let _ = Color::Blue;
+//! # }
+//! ```
+//!
+//! # Unique Identifiers
+//!
+//! `#name = unique!("prefix")` resolves to `"prefix_N"`, where `N` comes from a process-global
+//! counter that is bumped once per declaration (not per use-site) the first time it's processed.
+//! This guarantees every `unique!`-declared identifier in the compilation is distinct, even across
+//! separate `ident_str!` invocations:
+//!
+//! ```
+//! ident_str::ident_str! {
+//!     #name = unique!("tmp") =>
+//!     fn #name() -> &'static str {
+//!         stringify!(#name)
+//!     }
+//! }
+//!
+//! # fn main() {
+//! #     assert!(tmp_0().starts_with("tmp_"));
+//! # }
+//! ```
+//!
+//! Note that the exact numeric suffix depends on the order in which `unique!` declarations are
+//! expanded across the whole compilation, so it is not stable across compiler versions, build
+//! order, or even separate builds of the same source — only the distinctness is guaranteed.
 
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
 
 use macro_string::MacroString;
 use proc_macro::TokenStream;
 use proc_macro2::{Group, Ident, TokenStream as TokenStream2, TokenTree};
 use quote::{ToTokens, TokenStreamExt};
 use syn::{
-    Token,
+    LitStr, Token,
     parse::{Parse, ParseStream},
     parse_macro_input,
 };
 
 enum Value {
-    MacroString(MacroString),
+    MacroString {
+        primary: MacroString,
+        fallback: Option<MacroString>,
+    },
+    /// A list of values, bound to an ident that may only be used inside a `#*{ ... }` repetition
+    /// group.
+    List(Vec<MacroString>),
+    /// A `unique!("prefix")` declaration. Resolved to a `MacroString` of `"prefix_N"` exactly
+    /// once, when the declaration is processed, using a process-global counter.
+    Unique(String),
     None,
 }
 
 impl Value {
+    /// Returns the primary value, falling back to the fallback value (if any) when the primary
+    /// does not evaluate to a legal identifier.  Returns `None` for `Value::List`, since a list
+    /// only has a value once bound to an element inside a repetition group.
     pub fn to_string(&self) -> Option<String> {
         match self {
-            Value::MacroString(MacroString(n)) => Some(n.clone()),
-            Value::None => None,
+            Value::MacroString { primary, fallback } => {
+                let MacroString(primary) = primary;
+                if syn::parse_str::<Ident>(primary).is_ok() {
+                    Some(primary.clone())
+                } else if let Some(MacroString(fallback)) = fallback {
+                    Some(fallback.clone())
+                } else {
+                    Some(primary.clone())
+                }
+            }
+            Value::List(_) | Value::Unique(_) | Value::None => None,
         }
     }
 }
@@ -103,8 +241,42 @@ impl Parse for Value {
             .is_ok()
         {
             Ok(Value::None)
+        } else if input.peek(syn::token::Bracket) {
+            let content;
+            syn::bracketed!(content in input);
+            let mut items = Vec::new();
+            while !content.is_empty() {
+                items.push(content.parse()?);
+                if content.peek(Token![,]) {
+                    let _: Token![,] = content.parse()?;
+                } else {
+                    break;
+                }
+            }
+            Ok(Value::List(items))
+        } else if input.fork().call(|fork| {
+            let ident: Ident = fork.parse()?;
+            if ident == "unique" && fork.peek(Token![!]) {
+                Ok(())
+            } else {
+                Err(fork.error("expected `unique!`"))
+            }
+        }).is_ok() {
+            let _ident: Ident = input.parse()?;
+            let _bang: Token![!] = input.parse()?;
+            let content;
+            syn::parenthesized!(content in input);
+            let prefix: LitStr = content.parse()?;
+            Ok(Value::Unique(prefix.value()))
         } else {
-            Ok(Value::MacroString(input.parse()?))
+            let primary = input.parse()?;
+            let fallback = if input.peek(Token![|]) {
+                let _: Token![|] = input.parse()?;
+                Some(input.parse()?)
+            } else {
+                None
+            };
+            Ok(Value::MacroString { primary, fallback })
         }
     }
 }
@@ -182,6 +354,10 @@ impl Parse for Decls {
     }
 }
 
+/// Process-global counter backing `unique!("prefix")` declarations, bumped once per declaration
+/// (not per use-site) as the declaration is processed.
+static UNIQUE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
 fn append_error(errors: &mut Option<syn::Error>, new: syn::Error) {
     if let Some(errors) = errors {
         errors.combine(new);
@@ -190,10 +366,296 @@ fn append_error(errors: &mut Option<syn::Error>, new: syn::Error) {
     }
 }
 
+/// Splits a string into "words" on `_`/`-` separators and on lower->upper case boundaries,
+/// e.g. `fooBar` -> `foo`, `Bar` and `HTTPServer` -> `HTTP`, `Server`.
+fn split_words(s: &str) -> Vec<String> {
+    let chars: Vec<char> = s.chars().collect();
+    let mut words = Vec::new();
+    let mut current = String::new();
+    for i in 0..chars.len() {
+        let c = chars[i];
+        if c == '_' || c == '-' {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            continue;
+        }
+        if c.is_uppercase() && !current.is_empty() {
+            let prev = chars[i - 1];
+            let next = chars.get(i + 1).copied();
+            let break_before = prev.is_lowercase()
+                || (prev.is_uppercase() && next.is_some_and(char::is_lowercase));
+            if break_before {
+                words.push(std::mem::take(&mut current));
+            }
+        }
+        current.push(c);
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+    words
+}
+
+/// Capitalises the first character of `word` and lowercases the rest.
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().collect::<String>() + &chars.as_str().to_lowercase(),
+        None => String::new(),
+    }
+}
+
+/// Applies a `:@modifier` adornment (`upper`, `lower`, `snake`, `camel`/`pascal`, `screaming`) to
+/// `value`, returning `None` if `modifier` is not recognised.
+fn apply_modifier(value: &str, modifier: &str) -> Option<String> {
+    Some(match modifier {
+        "upper" => value.to_uppercase(),
+        "lower" => value.to_lowercase(),
+        "snake" => split_words(value)
+            .iter()
+            .map(|w| w.to_lowercase())
+            .collect::<Vec<_>>()
+            .join("_"),
+        "screaming" => split_words(value)
+            .iter()
+            .map(|w| w.to_uppercase())
+            .collect::<Vec<_>>()
+            .join("_"),
+        "camel" => split_words(value)
+            .iter()
+            .enumerate()
+            .map(|(i, w)| if i == 0 { w.to_lowercase() } else { capitalize(w) })
+            .collect(),
+        "pascal" => split_words(value).iter().map(|w| capitalize(w)).collect(),
+        _ => return None,
+    })
+}
+
+/// If `group` is a `[< ... >]` concatenation group, returns the stream between the `<` and `>`.
+/// Plain `[ ... ]` groups (e.g. the body of an attribute like `#[derive(...)]`) are left alone so
+/// that substituting inside attributes keeps working as it always has.
+fn concat_group_inner(group: &Group) -> Option<TokenStream2> {
+    if group.delimiter() != proc_macro2::Delimiter::Bracket {
+        return None;
+    }
+    let tokens: Vec<TokenTree> = group.stream().into_iter().collect();
+    let is_lt = |t: &TokenTree| matches!(t, TokenTree::Punct(p) if p.as_char() == '<');
+    let is_gt = |t: &TokenTree| matches!(t, TokenTree::Punct(p) if p.as_char() == '>');
+    if tokens.len() >= 2 && is_lt(&tokens[0]) && is_gt(&tokens[tokens.len() - 1]) {
+        Some(tokens[1..tokens.len() - 1].iter().cloned().collect())
+    } else {
+        None
+    }
+}
+
+/// Resolves the contents of a `#[< ... >]` concatenation group into a single identifier string,
+/// gluing together declared variables (`#ident`), string literals and bare idents in order. A
+/// list-bound `#ident` is looked up in `overlay` first, mirroring `translate_stream`.
+fn build_concat_ident(
+    stream: TokenStream2,
+    map: &HashMap<String, Decl>,
+    overlay: &HashMap<String, String>,
+) -> syn::Result<String> {
+    let mut result = String::new();
+    let mut iter = stream.into_iter().peekable();
+    while let Some(tok) = iter.next() {
+        match tok {
+            TokenTree::Punct(ref p) if p.as_char() == '#' => {
+                let Some(TokenTree::Ident(ident)) = iter.next() else {
+                    return Err(syn::Error::new_spanned(tok, "Expected identifier after '#'"));
+                };
+                let strident = ident.to_string();
+                if let Some(value) = overlay.get(&strident) {
+                    result.push_str(value);
+                    continue;
+                }
+                let Some(decl) = map.get(&strident) else {
+                    return Err(syn::Error::new_spanned(
+                        ident,
+                        format!("Unknown ident variable '#{strident}'"),
+                    ));
+                };
+                let Some(value) = decl.value.to_string() else {
+                    return Err(syn::Error::new_spanned(
+                        ident,
+                        format!("Cannot concatenate unset variable '#{strident}'"),
+                    ));
+                };
+                result.push_str(&value);
+            }
+            TokenTree::Literal(lit) => {
+                if let Ok(lit_str) = syn::parse2::<LitStr>(lit.to_token_stream()) {
+                    result.push_str(&lit_str.value());
+                } else {
+                    result.push_str(&lit.to_string());
+                }
+            }
+            TokenTree::Ident(ident) => result.push_str(&ident.to_string()),
+            other => {
+                return Err(syn::Error::new_spanned(
+                    other,
+                    "Unexpected token in identifier concatenation",
+                ));
+            }
+        }
+    }
+    Ok(result)
+}
+
+type TokenIter = std::iter::Peekable<proc_macro2::token_stream::IntoIter>;
+
+/// Substitutes a resolved `value` for `#ident`, applying a trailing `:@modifier` (if present) and
+/// validating the result, emitting the same "Invalid identifier" diagnostic as a declaration-time
+/// failure would.
+///
+/// The modifier marker is `:@modifier` rather than bare `:modifier` because the latter is
+/// indistinguishable from a real Rust `: Trait` bound or `: Type` annotation that happens to follow
+/// a substituted identifier (e.g. `fn helper<#name: lower>(...)`) — `:@` is not valid Rust syntax
+/// in those positions, so it can never collide.
+fn substitute_ident(
+    ident: Ident,
+    value: String,
+    iter: &mut TokenIter,
+    out: &mut TokenStream2,
+    errors: &mut Option<syn::Error>,
+) {
+    let mut value = value;
+    let mut modifier_tokens = TokenStream2::new();
+    let mut lookahead = iter.clone();
+    if let Some(TokenTree::Punct(colon)) = lookahead.peek() {
+        if colon.as_char() == ':' {
+            let colon = lookahead.next().unwrap();
+            if let Some(TokenTree::Punct(at)) = lookahead.peek().cloned() {
+                if at.as_char() == '@' {
+                    lookahead.next();
+                    if let Some(TokenTree::Ident(modifier_ident)) = lookahead.peek().cloned() {
+                        if let Some(transformed) =
+                            apply_modifier(&value, &modifier_ident.to_string())
+                        {
+                            lookahead.next();
+                            modifier_tokens.append(colon);
+                            modifier_tokens.append(TokenTree::Punct(at));
+                            modifier_tokens.append(TokenTree::Ident(modifier_ident));
+                            *iter = lookahead;
+                            value = transformed;
+                        }
+                    }
+                }
+            }
+        }
+    }
+    if syn::parse_str::<Ident>(&value).is_ok() {
+        out.append(TokenTree::Ident(Ident::new(&value, ident.span())));
+    } else {
+        let mut tokens = TokenStream2::new();
+        tokens.append(TokenTree::Ident(ident));
+        tokens.extend(modifier_tokens);
+        append_error(
+            errors,
+            syn::Error::new_spanned(tokens, format!("Invalid identifier: {:?}", value)),
+        );
+    }
+}
+
+/// Collects the (first-occurrence) idents of every list-bound variable referenced anywhere in
+/// `stream`, recursing into nested groups.
+fn collect_list_vars(stream: &TokenStream2, map: &HashMap<String, Decl>, found: &mut Vec<Ident>) {
+    let mut iter = stream.clone().into_iter().peekable();
+    while let Some(tok) = iter.next() {
+        match tok {
+            TokenTree::Group(g) => collect_list_vars(&g.stream(), map, found),
+            TokenTree::Punct(ref p) if p.as_char() == '#' => {
+                if let Some(TokenTree::Ident(_)) = iter.peek() {
+                    let Some(TokenTree::Ident(ident)) = iter.next() else {
+                        unreachable!();
+                    };
+                    let strident = ident.to_string();
+                    if matches!(map.get(&strident), Some(Decl { value: Value::List(_), .. }))
+                        && !found.iter().any(|i| i == &ident)
+                    {
+                        found.push(ident);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Expands a `#*{ ... }` repetition group, re-emitting its body once per element of the
+/// list-bound variables it references.
+fn expand_repetition(
+    group: &Group,
+    map: &HashMap<String, Decl>,
+    errors: &mut Option<syn::Error>,
+    overlay: &HashMap<String, String>,
+) -> TokenStream2 {
+    let mut vars = Vec::new();
+    collect_list_vars(&group.stream(), map, &mut vars);
+    if vars.is_empty() {
+        append_error(
+            errors,
+            syn::Error::new_spanned(
+                group,
+                "Repetition group `#*{ ... }` does not reference any list-bound variable",
+            ),
+        );
+        return TokenStream2::new();
+    }
+
+    let lens: Vec<(Ident, usize)> = vars
+        .into_iter()
+        .map(|ident| {
+            let len = match &map.get(&ident.to_string()).unwrap().value {
+                Value::List(items) => items.len(),
+                _ => unreachable!("collect_list_vars only returns list-bound idents"),
+            };
+            (ident, len)
+        })
+        .collect();
+    let first_len = lens[0].1;
+    if lens.iter().any(|(_, len)| *len != first_len) {
+        for (ident, len) in &lens {
+            append_error(
+                errors,
+                syn::Error::new_spanned(
+                    ident,
+                    format!(
+                        "List-bound variable '#{ident}' has {len} elements, but all list-bound \
+                         variables referenced in the same repetition group must have equal length"
+                    ),
+                ),
+            );
+        }
+        return TokenStream2::new();
+    }
+
+    let mut out = TokenStream2::new();
+    for i in 0..first_len {
+        let mut scoped_overlay = overlay.clone();
+        for (ident, _) in &lens {
+            let strident = ident.to_string();
+            if let Value::List(items) = &map.get(&strident).unwrap().value {
+                let MacroString(s) = &items[i];
+                scoped_overlay.insert(strident, s.clone());
+            }
+        }
+        out.extend(translate_stream(
+            group.stream(),
+            map,
+            errors,
+            &scoped_overlay,
+        ));
+    }
+    out
+}
+
 fn translate_stream(
     stream: TokenStream2,
     map: &HashMap<String, Decl>,
     errors: &mut Option<syn::Error>,
+    overlay: &HashMap<String, String>,
 ) -> TokenStream2 {
     let mut out = TokenStream2::new();
     let mut iter = stream.into_iter().peekable();
@@ -203,7 +665,7 @@ fn translate_stream(
             TokenTree::Group(group) => {
                 let mut group = Group::new(
                     group.delimiter(),
-                    translate_stream(group.stream(), map, errors),
+                    translate_stream(group.stream(), map, errors, overlay),
                 );
                 group.set_span(group.span());
                 out.append(TokenTree::Group(group));
@@ -215,13 +677,26 @@ fn translate_stream(
                     };
                     let strident = ident.to_string();
                     if let Some(decl) = map.get(&strident) {
-                        let ident = if let Some(value) = &decl.value.to_string() {
-                            Ident::new(value, ident.span()) // this won't panic, as we checked the string in main
+                        if matches!(&decl.value, Value::List(_)) {
+                            if let Some(value) = overlay.get(&strident).cloned() {
+                                substitute_ident(ident, value, &mut iter, &mut out, errors);
+                            } else {
+                                append_error(
+                                    errors,
+                                    syn::Error::new_spanned(
+                                        ident,
+                                        format!(
+                                            "List-bound variable '#{strident}' can only be used inside a repetition group (`#*{{ ... }}`)"
+                                        ),
+                                    ),
+                                );
+                            }
+                        } else if let Some(value) = decl.value.to_string() {
+                            substitute_ident(ident, value, &mut iter, &mut out, errors);
                         } else {
                             out.append(tok);
-                            ident
-                        };
-                        out.append(TokenTree::Ident(ident));
+                            out.append(TokenTree::Ident(ident));
+                        }
                     } else {
                         let mut tokens = TokenStream2::new();
                         tokens.append(tok);
@@ -239,6 +714,46 @@ fn translate_stream(
                         );
                         continue;
                     }
+                } else if let Some(TokenTree::Punct(star)) = iter.peek().cloned() {
+                    if star.as_char() == '*' {
+                        let mut lookahead = iter.clone();
+                        lookahead.next();
+                        if let Some(TokenTree::Group(g)) = lookahead.peek() {
+                            if g.delimiter() == proc_macro2::Delimiter::Brace {
+                                iter.next();
+                                let Some(TokenTree::Group(group)) = iter.next() else {
+                                    unreachable!();
+                                };
+                                out.extend(expand_repetition(&group, map, errors, overlay));
+                            } else {
+                                out.append(tok);
+                            }
+                        } else {
+                            out.append(tok);
+                        }
+                    } else {
+                        out.append(tok);
+                    }
+                } else if let Some(inner) = iter.peek().and_then(|t| match t {
+                    TokenTree::Group(g) => concat_group_inner(g),
+                    _ => None,
+                }) {
+                    let Some(TokenTree::Group(group)) = iter.next() else {
+                        unreachable!();
+                    };
+                    match build_concat_ident(inner, map, overlay) {
+                        Ok(value) if syn::parse_str::<Ident>(&value).is_ok() => {
+                            out.append(TokenTree::Ident(Ident::new(&value, group.span())));
+                        }
+                        Ok(value) => append_error(
+                            errors,
+                            syn::Error::new_spanned(
+                                group,
+                                format!("Invalid identifier: {:?}", value),
+                            ),
+                        ),
+                        Err(e) => append_error(errors, e),
+                    }
                 } else {
                     out.append(tok);
                 }
@@ -274,7 +789,15 @@ pub fn ident_str(input: TokenStream) -> TokenStream {
     let mut map = HashMap::<String, Decl>::with_capacity(decls.decls.len());
     let mut errors: Option<syn::Error> = None;
     let mut can_continue = true;
-    for d in decls.decls.into_iter() {
+    for mut d in decls.decls.into_iter() {
+        if let Value::Unique(prefix) = &d.value {
+            let n = UNIQUE_COUNTER.fetch_add(1, Ordering::Relaxed);
+            d.value = Value::MacroString {
+                primary: MacroString(format!("{prefix}_{n}")),
+                fallback: None,
+            };
+        }
+
         let strident = d.ident.to_string();
         let existing = map.get(&strident);
         if existing.is_some() {
@@ -303,7 +826,7 @@ pub fn ident_str(input: TokenStream) -> TokenStream {
     }
 
     let mut tokens = if can_continue {
-        translate_stream(decls.body, &map, &mut errors)
+        translate_stream(decls.body, &map, &mut errors, &HashMap::new())
     } else {
         debug_assert!(errors.is_some());
         TokenStream2::new()
@@ -0,0 +1,74 @@
+// camel/pascal/screaming modifiers deliberately produce non-snake-case identifiers.
+#![allow(non_snake_case)]
+
+ident_str::ident_str! {
+    #name = "fooBar_baz" =>
+    fn #name:@snake() -> &'static str {
+        stringify!(#name:@snake)
+    }
+}
+
+ident_str::ident_str! {
+    #name = "foo_bar_baz" =>
+    fn #name:@camel() -> &'static str {
+        stringify!(#name:@camel)
+    }
+}
+
+ident_str::ident_str! {
+    #name = "foo_bar_baz" =>
+    fn #name:@pascal() -> &'static str {
+        stringify!(#name:@pascal)
+    }
+}
+
+ident_str::ident_str! {
+    #name = "HTTPServer" =>
+    fn #name:@screaming() -> &'static str {
+        stringify!(#name:@screaming)
+    }
+}
+
+#[test]
+fn snake_case() {
+    assert_eq!(foo_bar_baz(), "foo_bar_baz");
+}
+
+#[test]
+fn camel_case() {
+    assert_eq!(fooBarBaz(), "fooBarBaz");
+}
+
+#[test]
+fn pascal_case() {
+    assert_eq!(FooBarBaz(), "FooBarBaz");
+}
+
+#[test]
+fn screaming_case() {
+    assert_eq!(HTTP_SERVER(), "HTTP_SERVER");
+}
+
+// A real `#name: Trait` bound that happens to spell a modifier name must survive untouched,
+// since `:@modifier` (not bare `:modifier`) is the only syntax that triggers case conversion.
+struct Thing;
+
+#[allow(non_camel_case_types)]
+trait lower {}
+impl lower for Thing {}
+
+fn use_lower<T: lower>(_: &T) -> &'static str {
+    "used lower bound"
+}
+
+ident_str::ident_str! {
+    #name = "Thing" =>
+    fn helper<#name: lower>(x: #name) -> &'static str {
+        use_lower(&x)
+    }
+}
+
+#[test]
+fn trait_bound_colon_is_not_swallowed() {
+    assert_eq!(helper(Thing), "used lower bound");
+}
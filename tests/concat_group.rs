@@ -0,0 +1,29 @@
+ident_str::ident_str! {
+    #prefix = "foo",
+    #name = "bar"
+    =>
+    fn #[< #prefix "_" #name "_handler" >]() -> &'static str {
+        stringify!(#[< #prefix "_" #name "_handler" >])
+    }
+}
+
+#[test]
+fn concat_group() {
+    assert_eq!(foo_bar_handler(), "foo_bar_handler");
+}
+
+// A plain `#[...]` attribute group (not `#[< ... >]`) must keep working, since it's indistinguishable
+// from an attribute written directly in the macro body.
+ident_str::ident_str! {
+    #derive = "derive",
+    #debug = "Debug"
+    =>
+    #[#derive(#debug)]
+    struct Tagged;
+}
+
+#[test]
+fn attributes_inside_body_still_work() {
+    let _: Tagged = Tagged;
+    assert_eq!(format!("{:?}", Tagged), "Tagged");
+}
@@ -0,0 +1,25 @@
+ident_str::ident_str! {
+    #name = "valid_primary" | "unused_fallback"
+    =>
+    fn #name() -> &'static str {
+        stringify!(#name)
+    }
+}
+
+ident_str::ident_str! {
+    #name = concat!("") | "used_fallback"
+    =>
+    fn #name() -> &'static str {
+        stringify!(#name)
+    }
+}
+
+#[test]
+fn primary_used_when_valid() {
+    assert_eq!(valid_primary(), "valid_primary");
+}
+
+#[test]
+fn fallback_used_when_primary_invalid() {
+    assert_eq!(used_fallback(), "used_fallback");
+}
@@ -0,0 +1,53 @@
+// `make_Red` etc. below deliberately keep the list element's casing.
+#![allow(non_snake_case)]
+
+ident_str::ident_str! {
+    #variant = ["Red", "Green", "Blue"]
+    =>
+    #[derive(Debug, PartialEq)]
+    enum Color2 {
+        #*{ #variant, }
+    }
+}
+
+#[test]
+fn repetition_expands_once_per_element() {
+    assert_eq!(Color2::Red, Color2::Red);
+    assert_eq!(Color2::Green, Color2::Green);
+    assert_eq!(Color2::Blue, Color2::Blue);
+}
+
+ident_str::ident_str! {
+    #variant = ["Red", "Green", "Blue"],
+    #fn_name = ["describe_red", "describe_green", "describe_blue"]
+    =>
+    #*{
+        fn #fn_name() -> &'static str {
+            stringify!(#variant)
+        }
+    }
+}
+
+#[test]
+fn repetition_substitutes_multiple_lists_in_lockstep() {
+    assert_eq!(describe_red(), "Red");
+    assert_eq!(describe_green(), "Green");
+    assert_eq!(describe_blue(), "Blue");
+}
+
+ident_str::ident_str! {
+    #variant = ["Red", "Green", "Blue"]
+    =>
+    #*{
+        fn #[< "make_" #variant >]() -> &'static str {
+            stringify!(#variant)
+        }
+    }
+}
+
+#[test]
+fn concat_group_inside_repetition() {
+    assert_eq!(make_Red(), "Red");
+    assert_eq!(make_Green(), "Green");
+    assert_eq!(make_Blue(), "Blue");
+}
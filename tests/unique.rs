@@ -0,0 +1,22 @@
+ident_str::ident_str! {
+    #name = unique!("tmp") =>
+    fn #name() -> &'static str {
+        stringify!(#name)
+    }
+}
+
+ident_str::ident_str! {
+    #name = unique!("tmp") =>
+    fn #name() -> &'static str {
+        stringify!(#name)
+    }
+}
+
+#[test]
+fn unique_identifiers_are_distinct() {
+    let a = tmp_0();
+    let b = tmp_1();
+    assert_ne!(a, b);
+    assert!(a.starts_with("tmp_"));
+    assert!(b.starts_with("tmp_"));
+}